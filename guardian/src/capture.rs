@@ -0,0 +1,90 @@
+//! Async capture of a managed process's stdout/stderr.
+//!
+//! `start_process` used to redirect both streams to `/dev/null` to avoid the
+//! classic pipe-buffer deadlock (a full pipe blocks the child when nobody
+//! drains it). That forced `wait_for_log_pattern` to depend on an external
+//! `log_file` and otherwise degrade to a blind sleep.
+//!
+//! Instead we pipe both streams and spawn a reader task per stream that
+//! continuously drains the pipe line-by-line into a bounded ring buffer — so it
+//! never fills — and broadcasts each line to any live subscribers (readiness
+//! pattern matching). The last N lines stay available for status/debugging.
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::process::{ChildStderr, ChildStdout};
+use tokio::sync::broadcast;
+
+/// Maximum lines retained in the per-process ring buffer.
+const RING_CAPACITY: usize = 1000;
+/// Capacity of the live broadcast channel (lines, not bytes).
+const BROADCAST_CAPACITY: usize = 256;
+
+/// Shared capture state for one process: a bounded backlog plus a live feed.
+#[derive(Debug)]
+pub struct OutputCapture {
+    ring: Mutex<VecDeque<String>>,
+    tx: broadcast::Sender<String>,
+}
+
+impl OutputCapture {
+    pub fn new() -> std::sync::Arc<Self> {
+        let (tx, _rx) = broadcast::channel(BROADCAST_CAPACITY);
+        std::sync::Arc::new(Self {
+            ring: Mutex::new(VecDeque::with_capacity(RING_CAPACITY)),
+            tx,
+        })
+    }
+
+    /// Subscribe to the live line feed (used by readiness pattern matching).
+    pub fn subscribe(&self) -> broadcast::Receiver<String> {
+        self.tx.subscribe()
+    }
+
+    /// Return the most recent `lines` captured lines, oldest first.
+    pub fn tail(&self, lines: usize) -> Vec<String> {
+        let ring = self.ring.lock().unwrap();
+        let start = ring.len().saturating_sub(lines);
+        ring.iter().skip(start).cloned().collect()
+    }
+
+    fn push(&self, line: String) {
+        {
+            let mut ring = self.ring.lock().unwrap();
+            if ring.len() == RING_CAPACITY {
+                ring.pop_front();
+            }
+            ring.push_back(line.clone());
+        }
+        // A send error just means no live subscribers — the ring still has it.
+        let _ = self.tx.send(line);
+    }
+}
+
+/// Attach drain tasks to a freshly spawned child's piped streams. Each task
+/// reads until EOF (process exit) and exits cleanly afterwards.
+pub fn attach(
+    capture: &std::sync::Arc<OutputCapture>,
+    stdout: Option<ChildStdout>,
+    stderr: Option<ChildStderr>,
+) {
+    if let Some(out) = stdout {
+        let capture = capture.clone();
+        tokio::spawn(async move {
+            let mut lines = BufReader::new(out).lines();
+            while let Ok(Some(line)) = lines.next_line().await {
+                capture.push(line);
+            }
+        });
+    }
+    if let Some(err) = stderr {
+        let capture = capture.clone();
+        tokio::spawn(async move {
+            let mut lines = BufReader::new(err).lines();
+            while let Ok(Some(line)) = lines.next_line().await {
+                capture.push(line);
+            }
+        });
+    }
+}
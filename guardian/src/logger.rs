@@ -0,0 +1,71 @@
+use anyhow::{Context, Result};
+use std::fs::OpenOptions;
+use std::path::Path;
+use tracing::info;
+use tracing_subscriber::prelude::*;
+use tracing_subscriber::{fmt, EnvFilter};
+
+use crate::config::{LogBackend, LoggingConfig};
+
+// =============================================================================
+// Logging initialization
+// =============================================================================
+
+/// Initialize the global `tracing` subscriber for the selected backend.
+///
+/// The `file` backend (the default) writes to `logging.output`, matching the
+/// original flat-file behavior. Under launchctl/systemd the `syslog` and
+/// `journald` backends route records to the system journal instead, so logs
+/// land where the service manager expects them rather than in a stray file.
+pub fn init_logging(config: &LoggingConfig) -> Result<()> {
+    let filter = EnvFilter::try_from_default_env()
+        .unwrap_or_else(|_| EnvFilter::new(config.level.clone()));
+
+    let registry = tracing_subscriber::registry().with(filter);
+
+    match config.backend {
+        LogBackend::File => {
+            let path = Path::new(&config.output);
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent).ok();
+            }
+            let file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)
+                .with_context(|| format!("Failed to open log file: {}", config.output))?;
+
+            registry
+                .with(fmt::layer().with_ansi(false).with_writer(file))
+                .init();
+            info!("Logging to file: {}", config.output);
+        }
+        LogBackend::Syslog => {
+            let formatter = syslog::Formatter3164 {
+                facility: config.syslog_facility,
+                hostname: None,
+                process: "oc-guardian".to_string(),
+                pid: std::process::id(),
+            };
+            let writer = syslog::unix(formatter)
+                .context("Failed to connect to the local syslog socket")?;
+            registry
+                .with(tracing_syslog::layer(writer))
+                .init();
+            info!("Logging to syslog (facility: {:?})", config.syslog_facility);
+        }
+        LogBackend::Journald => {
+            // Pin SYSLOG_IDENTIFIER so `guardian logs` (journalctl -t oc-guardian)
+            // matches regardless of the invoked binary name; otherwise the sink
+            // defaults it to the executable's basename and the query finds
+            // nothing.
+            let layer = tracing_journald::layer()
+                .context("Failed to connect to journald")?
+                .with_syslog_identifier("oc-guardian".to_string());
+            registry.with(layer).init();
+            info!("Logging to journald");
+        }
+    }
+
+    Ok(())
+}
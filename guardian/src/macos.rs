@@ -1,8 +1,83 @@
-use anyhow::Result;
+use std::time::Duration;
+use thiserror::Error;
 use tracing::{info, warn};
 
+use crate::config::{Backend, MacOsConfig};
 
-use crate::config::MacOsConfig;
+/// Result alias for the sleep-prevention subsystem.
+pub type Result<T> = std::result::Result<T, SleepError>;
+
+/// Why sleep prevention failed.
+///
+/// Callers can branch on the cause — e.g. fall back from the pmset lid-close
+/// path to the idle-only backend when sudo isn't available — instead of
+/// parsing an opaque error string.
+#[derive(Debug, Error)]
+pub enum SleepError {
+    /// `pmset` could not disable sleep for lack of privileges (no passwordless
+    /// sudo and no askpass helper configured).
+    #[error("pmset requires elevated privileges (configure passwordless sudo or an askpass helper)")]
+    SudoRequired,
+
+    /// A required helper binary was not found on `PATH`.
+    #[error("required binary '{binary}' not found")]
+    BinaryNotFound { binary: String },
+
+    /// An IOKit power assertion returned a non-zero `IOReturn`.
+    #[error("IOKit power assertion failed (IOReturn {io_return})")]
+    AssertionFailed { io_return: i32 },
+
+    /// Sleep prevention is not implemented for the current platform.
+    #[error("sleep prevention is not supported on this platform")]
+    UnsupportedPlatform,
+
+    /// An underlying I/O error not covered by a more specific variant.
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+// =============================================================================
+// IOKit power-assertion FFI (native backend)
+// =============================================================================
+
+/// Minimal IOKit bindings for holding power assertions without spawning
+/// `caffeinate`. Linked against the IOKit framework on macOS.
+#[cfg(target_os = "macos")]
+mod iokit {
+    use std::os::raw::{c_char, c_int, c_void};
+
+    pub type IOPMAssertionID = u32;
+    pub type IOReturn = c_int;
+    pub type CFStringRef = *const c_void;
+    pub type CFAllocatorRef = *const c_void;
+
+    /// `kIOPMAssertionLevelOn` — the assertion is held.
+    pub const ASSERTION_LEVEL_ON: u32 = 255;
+    pub const KCF_STRING_ENCODING_UTF8: u32 = 0x0800_0100;
+    /// `kIOReturnSuccess`.
+    pub const IO_RETURN_SUCCESS: IOReturn = 0;
+
+    #[link(name = "IOKit", kind = "framework")]
+    extern "C" {
+        pub fn IOPMAssertionCreateWithName(
+            assertion_type: CFStringRef,
+            assertion_level: u32,
+            assertion_name: CFStringRef,
+            assertion_id: *mut IOPMAssertionID,
+        ) -> IOReturn;
+        pub fn IOPMAssertionRelease(assertion_id: IOPMAssertionID) -> IOReturn;
+    }
+
+    #[link(name = "CoreFoundation", kind = "framework")]
+    extern "C" {
+        pub fn CFStringCreateWithCString(
+            alloc: CFAllocatorRef,
+            c_str: *const c_char,
+            encoding: u32,
+        ) -> CFStringRef;
+        pub fn CFRelease(cf: *const c_void);
+    }
+}
 
 // =============================================================================
 // macOS Sleep Prevention (Sprint 3.5)
@@ -17,21 +92,139 @@ pub struct SleepPreventer {
     config: MacOsConfig,
     #[cfg(target_os = "macos")]
     caffeinate_child: Option<tokio::process::Child>,
-    #[cfg(not(target_os = "macos"))]
+    /// IOKit assertion IDs held by the native backend (empty when inactive).
+    #[cfg(target_os = "macos")]
+    assertion_ids: Vec<iokit::IOPMAssertionID>,
+    /// Auto-expire after this long, if set (caffeinate `-t`, else a timer task).
+    #[cfg(target_os = "macos")]
+    auto_timeout: Option<Duration>,
+    /// Hold only for the lifetime of this PID, if set (caffeinate `-w`, else a
+    /// polling task).
+    #[cfg(target_os = "macos")]
+    watch_pid: Option<u32>,
+    /// Background task enforcing the timeout / pid-watch lifetime for non-child
+    /// backends (IOKit/pmset).
+    #[cfg(target_os = "macos")]
+    lifetime_task: Option<tokio::task::JoinHandle<()>>,
+    /// Background task that drops the assertion when the power source falls
+    /// below the configured guard.
+    #[cfg(target_os = "macos")]
+    power_task: Option<tokio::task::JoinHandle<()>>,
+    /// Linux: a held `systemd-inhibit` child that owns the logind inhibitor
+    /// lock (`org.freedesktop.login1.Manager.Inhibit`, what="sleep:idle",
+    /// mode="block"); killing it closes the returned fd and releases the lock.
+    #[cfg(target_os = "linux")]
+    inhibit_child: Option<tokio::process::Child>,
+    /// Windows: whether `SetThreadExecutionState` is currently holding the
+    /// system (and display) requirement.
+    #[cfg(target_os = "windows")]
+    windows_active: bool,
+    #[cfg(not(any(target_os = "macos", target_os = "linux", target_os = "windows")))]
     _phantom: std::marker::PhantomData<()>,
 }
 
+// =============================================================================
+// Cross-platform inhibitor trait
+// =============================================================================
+
+/// Common interface for a platform's sleep-inhibition backend. macOS is driven
+/// by [`MacOsConfig`] (caffeinate/pmset/IOKit); Linux takes a logind inhibitor
+/// lock; Windows calls `SetThreadExecutionState`. [`SleepPreventer`] implements
+/// this uniformly so callers don't branch on the platform.
+#[allow(async_fn_in_trait)]
+pub trait SleepInhibitor {
+    async fn start(&mut self) -> Result<()>;
+    async fn stop(&mut self) -> Result<()>;
+    fn is_active(&self) -> bool;
+}
+
+impl SleepInhibitor for SleepPreventer {
+    async fn start(&mut self) -> Result<()> {
+        SleepPreventer::start(self).await
+    }
+    async fn stop(&mut self) -> Result<()> {
+        SleepPreventer::stop(self).await
+    }
+    fn is_active(&self) -> bool {
+        SleepPreventer::is_active(self)
+    }
+}
+
 impl SleepPreventer {
     pub fn new(config: MacOsConfig) -> Self {
         Self {
             config,
             #[cfg(target_os = "macos")]
             caffeinate_child: None,
-            #[cfg(not(target_os = "macos"))]
+            #[cfg(target_os = "macos")]
+            assertion_ids: Vec::new(),
+            #[cfg(target_os = "macos")]
+            auto_timeout: None,
+            #[cfg(target_os = "macos")]
+            watch_pid: None,
+            #[cfg(target_os = "macos")]
+            lifetime_task: None,
+            #[cfg(target_os = "macos")]
+            power_task: None,
+            #[cfg(target_os = "linux")]
+            inhibit_child: None,
+            #[cfg(target_os = "windows")]
+            windows_active: false,
+            #[cfg(not(any(target_os = "macos", target_os = "linux", target_os = "windows")))]
             _phantom: std::marker::PhantomData,
         }
     }
 
+    /// Start sleep prevention and automatically stop after `duration`.
+    ///
+    /// Useful for wrapping a finite job (build/render/backup): keep the Mac
+    /// awake for the run, then restore sleep without the caller managing
+    /// teardown. Maps to caffeinate `-t` for the caffeinate backend, or a
+    /// spawned timer task that releases the IOKit assertions (or restores
+    /// pmset) otherwise.
+    ///
+    /// Only the macOS backend enforces this lifetime; the Linux (logind) and
+    /// Windows backends have no equivalent, so off macOS this returns
+    /// [`SleepError::UnsupportedPlatform`] rather than starting and then holding
+    /// the inhibitor forever.
+    pub async fn start_with_timeout(&mut self, duration: Duration) -> Result<()> {
+        #[cfg(target_os = "macos")]
+        {
+            self.auto_timeout = Some(duration);
+            self.start().await?;
+            self.spawn_lifetime_task();
+            Ok(())
+        }
+        #[cfg(not(target_os = "macos"))]
+        {
+            let _ = duration;
+            Err(SleepError::UnsupportedPlatform)
+        }
+    }
+
+    /// Start sleep prevention and hold it only while `pid` is alive, releasing
+    /// automatically when that process exits. Maps to caffeinate `-w`, or a
+    /// polling task for the native backends.
+    ///
+    /// As with [`Self::start_with_timeout`], the pid-watch lifetime is enforced
+    /// only by the macOS backend; off macOS this returns
+    /// [`SleepError::UnsupportedPlatform`] instead of holding the inhibitor for
+    /// the process's whole life with no release on the watched pid's exit.
+    pub async fn start_watching_pid(&mut self, pid: u32) -> Result<()> {
+        #[cfg(target_os = "macos")]
+        {
+            self.watch_pid = Some(pid);
+            self.start().await?;
+            self.spawn_lifetime_task();
+            Ok(())
+        }
+        #[cfg(not(target_os = "macos"))]
+        {
+            let _ = pid;
+            Err(SleepError::UnsupportedPlatform)
+        }
+    }
+
     /// Start sleep prevention if enabled and on macOS
     pub async fn start(&mut self) -> Result<()> {
         if !self.config.prevent_sleep {
@@ -41,20 +234,51 @@ impl SleepPreventer {
 
         #[cfg(target_os = "macos")]
         {
-            if self.config.use_caffeinate {
-                self.start_caffeinate().await?;
-            } else {
-                self.start_pmset().await?;
+            // Refuse to start when the power source would have us drain a
+            // laptop battery past the configured guard.
+            if !self.power_allows_prevention().await {
+                warn!(
+                    "Refusing to prevent sleep: on battery and below the configured \
+                     power guard (require_ac_power={}, min_battery_percent={:?})",
+                    self.config.require_ac_power, self.config.min_battery_percent
+                );
+                return Ok(());
+            }
+
+            match self.config.backend {
+                // Native IOKit power assertions — no child process, queryable
+                // state, and no sudo for idle sleep.
+                Backend::IoKit => self.start_iokit()?,
+                Backend::Caffeinate => {
+                    if self.config.use_caffeinate {
+                        self.start_caffeinate().await?;
+                    } else {
+                        self.start_pmset().await?;
+                    }
+                }
             }
+
+            // Watch the power source so we drop the assertion if AC is pulled
+            // or the battery drains below the guard while prevention is active.
+            self.spawn_power_monitor();
         }
 
-        #[cfg(not(target_os = "macos"))]
+        #[cfg(target_os = "linux")]
         {
-            if self.config.prevent_sleep {
-                info!("Sleep prevention is only supported on macOS (current platform ignored)");
-            }
+            self.start_logind_inhibitor().await?;
+        }
+
+        #[cfg(target_os = "windows")]
+        {
+            self.start_windows_execution_state()?;
         }
 
+        #[cfg(not(any(target_os = "macos", target_os = "linux", target_os = "windows")))]
+        {
+            return Err(SleepError::UnsupportedPlatform);
+        }
+
+        #[allow(unreachable_code)]
         Ok(())
     }
 
@@ -66,13 +290,41 @@ impl SleepPreventer {
 
         #[cfg(target_os = "macos")]
         {
-            self.stop_caffeinate().await?;
+            // Cancel any lifetime timer/pid-watch task first so it doesn't
+            // double-release after we stop.
+            if let Some(task) = self.lifetime_task.take() {
+                task.abort();
+            }
+            if let Some(task) = self.power_task.take() {
+                task.abort();
+            }
+
+            match self.config.backend {
+                Backend::IoKit => self.stop_iokit(),
+                Backend::Caffeinate => {
+                    self.stop_caffeinate().await?;
+
+                    if self.config.restore_sleep_on_exit && !self.config.use_caffeinate {
+                        self.restore_pmset().await?;
+                    }
+                }
+            }
+        }
 
-            if self.config.restore_sleep_on_exit && !self.config.use_caffeinate {
-                self.restore_pmset().await?;
+        #[cfg(target_os = "linux")]
+        {
+            if let Some(mut child) = self.inhibit_child.take() {
+                info!("Releasing logind sleep inhibitor lock");
+                let _ = child.kill().await;
+                let _ = child.wait().await;
             }
         }
 
+        #[cfg(target_os = "windows")]
+        {
+            self.clear_windows_execution_state();
+        }
+
         Ok(())
     }
 
@@ -80,9 +332,18 @@ impl SleepPreventer {
     pub fn is_active(&self) -> bool {
         #[cfg(target_os = "macos")]
         {
-            self.caffeinate_child.is_some()
+            // A real held assertion (IOKit) or a running caffeinate child.
+            !self.assertion_ids.is_empty() || self.caffeinate_child.is_some()
         }
-        #[cfg(not(target_os = "macos"))]
+        #[cfg(target_os = "linux")]
+        {
+            self.inhibit_child.is_some()
+        }
+        #[cfg(target_os = "windows")]
+        {
+            self.windows_active
+        }
+        #[cfg(not(any(target_os = "macos", target_os = "linux", target_os = "windows")))]
         {
             false
         }
@@ -92,7 +353,220 @@ impl SleepPreventer {
     // macOS-specific implementations
     // =========================================================================
 
-    /// Start caffeinate process (-d: display, -i: idle sleep prevention only)
+    /// Whether the current power source satisfies the configured guard.
+    /// Returns true when no guard is set, or on AC, or on battery above the
+    /// `min_battery_percent` floor (and `require_ac_power` is false).
+    #[cfg(target_os = "macos")]
+    async fn power_allows_prevention(&self) -> bool {
+        if !self.config.require_ac_power && self.config.min_battery_percent.is_none() {
+            return true;
+        }
+        match read_power_state().await {
+            Some((on_ac, percent)) => {
+                if on_ac {
+                    return true;
+                }
+                if self.config.require_ac_power {
+                    return false;
+                }
+                match self.config.min_battery_percent {
+                    Some(min) => percent >= min,
+                    None => true,
+                }
+            }
+            // Couldn't determine power state — don't block prevention on it.
+            None => true,
+        }
+    }
+
+    /// Periodically re-check the power source and release the assertion if the
+    /// guard is no longer satisfied (AC unplugged or battery drained).
+    #[cfg(target_os = "macos")]
+    fn spawn_power_monitor(&mut self) {
+        if !self.config.require_ac_power && self.config.min_battery_percent.is_none() {
+            return;
+        }
+
+        let ids = self.assertion_ids.clone();
+        // The caffeinate/pmset backends hold no IOKit assertions, so releasing
+        // `ids` alone would leave sleep prevented after the guard trips. Capture
+        // what those backends need torn down too: the caffeinate child's pid
+        // (signalled directly — the task can't own the `Child`, which `stop`
+        // still reaps later) and whether pmset must restore `disablesleep`.
+        let caffeinate_pid = self.caffeinate_child.as_ref().and_then(|c| c.id());
+        let restore_pmset = self.config.backend == Backend::Caffeinate
+            && !self.config.use_caffeinate
+            && self.config.restore_sleep_on_exit;
+        let require_ac = self.config.require_ac_power;
+        let min_battery = self.config.min_battery_percent;
+
+        let handle = tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(Duration::from_secs(30));
+            loop {
+                ticker.tick().await;
+                if let Some((on_ac, percent)) = read_power_state().await {
+                    let ok = on_ac
+                        || (!require_ac && min_battery.map(|m| percent >= m).unwrap_or(true));
+                    if !ok {
+                        warn!(
+                            "Power guard tripped (on_ac={}, battery={}%); releasing sleep prevention",
+                            on_ac, percent
+                        );
+                        for id in &ids {
+                            unsafe {
+                                iokit::IOPMAssertionRelease(*id);
+                            }
+                        }
+                        if let Some(pid) = caffeinate_pid {
+                            // SIGTERM caffeinate so it drops its assertion.
+                            unsafe {
+                                libc::kill(pid as i32, libc::SIGTERM);
+                            }
+                        }
+                        if restore_pmset {
+                            let _ = tokio::process::Command::new("sudo")
+                                .args(["pmset", "-a", "disablesleep", "0"])
+                                .output()
+                                .await;
+                        }
+                        break;
+                    }
+                }
+            }
+        });
+        self.power_task = Some(handle);
+    }
+
+    /// Spawn a background task that enforces the configured lifetime for the
+    /// native backends (IOKit/pmset), which — unlike caffeinate — have no
+    /// built-in `-t`/`-w`. The task releases the held IOKit assertions (and, for
+    /// pmset with `restore_sleep_on_exit`, restores `disablesleep`) when the
+    /// timeout elapses or the watched process exits. No-op when caffeinate
+    /// itself runs, since it manages its own lifetime.
+    #[cfg(target_os = "macos")]
+    fn spawn_lifetime_task(&mut self) {
+        if self.config.backend == Backend::Caffeinate && self.config.use_caffeinate {
+            return;
+        }
+        if self.auto_timeout.is_none() && self.watch_pid.is_none() {
+            return;
+        }
+
+        let ids = self.assertion_ids.clone();
+        let timeout = self.auto_timeout;
+        let watch_pid = self.watch_pid;
+        // pmset holds no assertion ids; undo `disablesleep` as `stop` would.
+        let restore_pmset =
+            self.config.backend == Backend::Caffeinate && self.config.restore_sleep_on_exit;
+
+        let handle = tokio::spawn(async move {
+            match (timeout, watch_pid) {
+                (Some(dur), _) => {
+                    tokio::time::sleep(dur).await;
+                    info!("Sleep prevention auto-expired after {:?}", dur);
+                }
+                (None, Some(pid)) => {
+                    // Poll until the watched process exits.
+                    while unsafe { libc::kill(pid as i32, 0) } == 0 {
+                        tokio::time::sleep(Duration::from_secs(1)).await;
+                    }
+                    info!("Watched process {} exited; releasing assertions", pid);
+                }
+                (None, None) => return,
+            }
+            for id in ids {
+                unsafe {
+                    iokit::IOPMAssertionRelease(id);
+                }
+            }
+            if restore_pmset {
+                let _ = tokio::process::Command::new("sudo")
+                    .args(["pmset", "-a", "disablesleep", "0"])
+                    .output()
+                    .await;
+            }
+        });
+        self.lifetime_task = Some(handle);
+    }
+
+
+    /// Start the native IOKit backend by creating exactly the assertions the
+    /// config requests (idle/display/disk/system), so a caller that only needs
+    /// the disk spun up or only the display awake does not over-assert.
+    ///
+    /// NOTE: like caffeinate, IOKit idle assertions do NOT prevent lid-close
+    /// sleep — only `pmset -a disablesleep 1` does.
+    #[cfg(target_os = "macos")]
+    fn start_iokit(&mut self) -> Result<()> {
+        info!("Holding IOKit power assertions per config...");
+
+        // (enabled flag, assertion type) — mirrors the caffeinate matrix below.
+        let reason = "OC-Guardian keep-awake";
+        let matrix = [
+            (self.config.prevent_idle, "PreventUserIdleSystemSleep"),
+            (self.config.prevent_display, "PreventUserIdleDisplaySleep"),
+            (self.config.prevent_disk, "NoIdleSleepAssertion"),
+            (self.config.prevent_system, "PreventSystemSleep"),
+            (self.config.declare_user_active, "UserIsActive"),
+        ];
+        for (enabled, assertion_type) in matrix {
+            if enabled {
+                let id = self.create_assertion(assertion_type, reason)?;
+                self.assertion_ids.push(id);
+            }
+        }
+
+        if self.assertion_ids.is_empty() {
+            warn!("IOKit backend selected but no assertions enabled in config");
+        } else {
+            info!("IOKit assertions held: {:?}", self.assertion_ids);
+        }
+        Ok(())
+    }
+
+    /// Release all held IOKit assertions.
+    #[cfg(target_os = "macos")]
+    fn stop_iokit(&mut self) {
+        for id in self.assertion_ids.drain(..) {
+            let rc = unsafe { iokit::IOPMAssertionRelease(id) };
+            if rc != iokit::IO_RETURN_SUCCESS {
+                warn!("IOPMAssertionRelease({}) returned {}", id, rc);
+            }
+        }
+    }
+
+    /// Create a single IOKit power assertion of the given type, returning its ID.
+    #[cfg(target_os = "macos")]
+    fn create_assertion(&self, assertion_type: &str, reason: &str) -> Result<u32> {
+        // SAFETY: both CFStrings are created here and released before return;
+        // the assertion id is written by the call on success.
+        unsafe {
+            let type_cf = cf_string(assertion_type)?;
+            let name_cf = cf_string(reason)?;
+            let mut id: iokit::IOPMAssertionID = 0;
+            let rc = iokit::IOPMAssertionCreateWithName(
+                type_cf,
+                iokit::ASSERTION_LEVEL_ON,
+                name_cf,
+                &mut id,
+            );
+            iokit::CFRelease(type_cf);
+            iokit::CFRelease(name_cf);
+
+            if rc != iokit::IO_RETURN_SUCCESS {
+                warn!(
+                    "IOPMAssertionCreateWithName({}) failed with IOReturn {}",
+                    assertion_type, rc
+                );
+                return Err(SleepError::AssertionFailed { io_return: rc });
+            }
+            Ok(id)
+        }
+    }
+
+    /// Start caffeinate with exactly the assertions the config requests:
+    /// `-d` display, `-i` idle system, `-m` disk idle, `-s` system (AC only),
+    /// `-u` declare user active.
     /// WARNING: caffeinate does NOT prevent lid-close sleep regardless of flags.
     /// Use pmset method (use_caffeinate = false) for lid-close prevention.
     #[cfg(target_os = "macos")]
@@ -100,11 +574,47 @@ impl SleepPreventer {
         use tokio::process::Command;
 
         warn!("caffeinate does NOT prevent lid-close sleep. Consider use_caffeinate = false (pmset) instead.");
-        info!("Starting caffeinate to prevent idle sleep...");
 
-        let child = Command::new("caffeinate")
-            .arg("-di") // prevent display and idle sleep only (NOT lid-close)
-            .spawn()?;
+        // Assemble flags from the assertion matrix, defaulting to the historical
+        // `-di` when the caller enabled nothing explicitly.
+        let mut flags = String::new();
+        if self.config.prevent_display {
+            flags.push('d');
+        }
+        if self.config.prevent_idle {
+            flags.push('i');
+        }
+        if self.config.prevent_disk {
+            flags.push('m');
+        }
+        if self.config.prevent_system {
+            flags.push('s');
+        }
+        if self.config.declare_user_active {
+            flags.push('u');
+        }
+        if flags.is_empty() {
+            flags.push_str("di");
+        }
+
+        info!("Starting caffeinate -{} ...", flags);
+
+        let mut command = Command::new("caffeinate");
+        command.arg(format!("-{}", flags));
+        // Native lifetime controls: -t auto-expires, -w ties the assertion to
+        // another process. caffeinate manages teardown itself in these cases.
+        if let Some(dur) = self.auto_timeout {
+            command.arg("-t").arg(dur.as_secs().to_string());
+        }
+        if let Some(pid) = self.watch_pid {
+            command.arg("-w").arg(pid.to_string());
+        }
+        let child = command.spawn().map_err(|e| match e.kind() {
+            std::io::ErrorKind::NotFound => SleepError::BinaryNotFound {
+                binary: "caffeinate".to_string(),
+            },
+            _ => SleepError::Io(e),
+        })?;
 
         let pid = child.id();
         info!(
@@ -146,12 +656,13 @@ impl SleepPreventer {
 
         if output.status.success() {
             info!("pmset: sleep disabled on all power sources (including lid-close)");
+            Ok(())
         } else {
             let stderr = String::from_utf8_lossy(&output.stderr);
             warn!("pmset failed (requires sudo with NOPASSWD or askpass): {}", stderr);
+            // Surface the cause so the caller can degrade to the idle-only path.
+            Err(SleepError::SudoRequired)
         }
-
-        Ok(())
     }
 
     /// Restore pmset sleep settings on all power sources
@@ -175,6 +686,133 @@ impl SleepPreventer {
 
         Ok(())
     }
+
+    // =========================================================================
+    // Linux-specific implementation (logind inhibitor lock)
+    // =========================================================================
+
+    /// Take a logind sleep+idle inhibitor lock by holding a `systemd-inhibit`
+    /// child, which calls `org.freedesktop.login1.Manager.Inhibit` with
+    /// `what="sleep:idle"`, `mode="block"` and keeps the returned fd open for
+    /// its lifetime. Killing the child in `stop()` closes the fd and releases.
+    #[cfg(target_os = "linux")]
+    async fn start_logind_inhibitor(&mut self) -> Result<()> {
+        use tokio::process::Command;
+
+        info!("Taking logind sleep:idle inhibitor lock via systemd-inhibit...");
+
+        // `sleep infinity` just keeps the lock held until we kill the process.
+        let child = Command::new("systemd-inhibit")
+            .args([
+                "--what=sleep:idle",
+                "--who=OC-Guardian",
+                "--why=Keeping the system awake for managed processes",
+                "--mode=block",
+                "sleep",
+                "infinity",
+            ])
+            .spawn();
+
+        match child {
+            Ok(child) => {
+                self.inhibit_child = Some(child);
+                Ok(())
+            }
+            Err(e) => {
+                warn!("Failed to take logind inhibitor lock: {}", e);
+                Err(match e.kind() {
+                    std::io::ErrorKind::NotFound => SleepError::BinaryNotFound {
+                        binary: "systemd-inhibit".to_string(),
+                    },
+                    _ => SleepError::Io(e),
+                })
+            }
+        }
+    }
+
+    // =========================================================================
+    // Windows-specific implementation (SetThreadExecutionState)
+    // =========================================================================
+
+    /// Request that the system (and display) stay awake via
+    /// `SetThreadExecutionState(ES_CONTINUOUS | ES_SYSTEM_REQUIRED [| ES_DISPLAY_REQUIRED])`.
+    #[cfg(target_os = "windows")]
+    fn start_windows_execution_state(&mut self) -> Result<()> {
+        const ES_CONTINUOUS: u32 = 0x8000_0000;
+        const ES_SYSTEM_REQUIRED: u32 = 0x0000_0001;
+        const ES_DISPLAY_REQUIRED: u32 = 0x0000_0002;
+
+        let mut flags = ES_CONTINUOUS | ES_SYSTEM_REQUIRED;
+        if self.config.prevent_display {
+            flags |= ES_DISPLAY_REQUIRED;
+        }
+
+        // SAFETY: SetThreadExecutionState takes a flag bitmask and returns the
+        // previous state (0 on error).
+        let prev = unsafe { SetThreadExecutionState(flags) };
+        if prev == 0 {
+            return Err(SleepError::AssertionFailed { io_return: 0 });
+        }
+        self.windows_active = true;
+        info!("Windows execution state set (flags: {:#x})", flags);
+        Ok(())
+    }
+
+    /// Clear the keep-awake request by restoring `ES_CONTINUOUS` alone.
+    #[cfg(target_os = "windows")]
+    fn clear_windows_execution_state(&mut self) {
+        const ES_CONTINUOUS: u32 = 0x8000_0000;
+        if self.windows_active {
+            unsafe { SetThreadExecutionState(ES_CONTINUOUS) };
+            self.windows_active = false;
+            info!("Windows execution state cleared");
+        }
+    }
+}
+
+#[cfg(target_os = "windows")]
+#[link(name = "kernel32")]
+extern "system" {
+    fn SetThreadExecutionState(es_flags: u32) -> u32;
+}
+
+/// Create a CoreFoundation string from a Rust `&str`. The caller owns the
+/// returned reference and must `CFRelease` it.
+#[cfg(target_os = "macos")]
+unsafe fn cf_string(s: &str) -> Result<iokit::CFStringRef> {
+    use std::ffi::CString;
+    let c = CString::new(s).map_err(|_| SleepError::AssertionFailed { io_return: -1 })?;
+    let cf = iokit::CFStringCreateWithCString(
+        std::ptr::null(),
+        c.as_ptr(),
+        iokit::KCF_STRING_ENCODING_UTF8,
+    );
+    if cf.is_null() {
+        warn!("CFStringCreateWithCString returned null for {:?}", s);
+        return Err(SleepError::AssertionFailed { io_return: -1 });
+    }
+    Ok(cf)
+}
+
+/// Read the current power source via `pmset -g batt`, returning
+/// `(on_ac_power, battery_percent)`. Returns `None` when the state can't be
+/// parsed (e.g. a desktop with no battery, or pmset unavailable).
+#[cfg(target_os = "macos")]
+async fn read_power_state() -> Option<(bool, u8)> {
+    use tokio::process::Command;
+
+    let output = Command::new("pmset").args(["-g", "batt"]).output().await.ok()?;
+    let text = String::from_utf8_lossy(&output.stdout);
+
+    // Example: "Now drawing from 'AC Power'\n -InternalBattery-0 ... 82%; ..."
+    let on_ac = text.contains("AC Power");
+    let percent = text
+        .split(';')
+        .next()
+        .and_then(|seg| seg.rsplit(char::is_whitespace).next())
+        .and_then(|tok| tok.trim_end_matches('%').parse::<u8>().ok())?;
+
+    Some((on_ac, percent))
 }
 
 // =============================================================================
@@ -191,6 +829,14 @@ mod tests {
             prevent_sleep: false,
             use_caffeinate: true,
             restore_sleep_on_exit: true,
+            backend: Backend::Caffeinate,
+            prevent_display: true,
+            prevent_idle: true,
+            prevent_disk: false,
+            prevent_system: false,
+            declare_user_active: false,
+            min_battery_percent: None,
+            require_ac_power: false,
         };
         let preventer = SleepPreventer::new(config);
         assert!(!preventer.is_active());
@@ -202,6 +848,14 @@ mod tests {
             prevent_sleep: true,
             use_caffeinate: true,
             restore_sleep_on_exit: true,
+            backend: Backend::Caffeinate,
+            prevent_display: true,
+            prevent_idle: true,
+            prevent_disk: false,
+            prevent_system: false,
+            declare_user_active: false,
+            min_battery_percent: None,
+            require_ac_power: false,
         };
         let preventer = SleepPreventer::new(config);
 
@@ -216,6 +870,14 @@ mod tests {
             prevent_sleep: false,
             use_caffeinate: true,
             restore_sleep_on_exit: true,
+            backend: Backend::Caffeinate,
+            prevent_display: true,
+            prevent_idle: true,
+            prevent_disk: false,
+            prevent_system: false,
+            declare_user_active: false,
+            min_battery_percent: None,
+            require_ac_power: false,
         };
         let mut preventer = SleepPreventer::new(config);
         preventer.start().await.unwrap();
@@ -228,6 +890,14 @@ mod tests {
             prevent_sleep: false,
             use_caffeinate: true,
             restore_sleep_on_exit: true,
+            backend: Backend::Caffeinate,
+            prevent_display: true,
+            prevent_idle: true,
+            prevent_disk: false,
+            prevent_system: false,
+            declare_user_active: false,
+            min_battery_percent: None,
+            require_ac_power: false,
         };
         let mut preventer = SleepPreventer::new(config);
         // Should not error
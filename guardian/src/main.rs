@@ -1,15 +1,18 @@
 #![allow(dead_code)]
 
+mod capture;
 mod compression;
 mod config;
 mod health;
 mod log_rotation;
 mod logger;
 mod notification;
+mod pidfd;
 mod process;
 mod recovery;
+mod socket;
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use clap::{Parser, Subcommand};
 use colored::Colorize;
 use comfy_table::{modifiers::UTF8_ROUND_CORNERS, presets::UTF8_FULL, Cell, Color, Table};
@@ -20,7 +23,7 @@ use tokio::sync::Mutex;
 use tracing::{error, info, warn};
 
 use crate::compression::CompressionManager;
-use crate::config::{load_config, GuardianConfig};
+use crate::config::{load_config, GuardianConfig, LogBackend};
 use crate::health::{HealthChecker, HealthStatus};
 use crate::log_rotation::LogRotator;
 use crate::notification::{EventType, NotificationEvent, NotificationManager, Severity};
@@ -359,12 +362,22 @@ async fn handle_stop(config: GuardianConfig) -> Result<()> {
             unsafe {
                 libc::kill(guardian_pid as i32, libc::SIGTERM);
             }
-            // Wait briefly for guardian to shut down gracefully
-            // (it will stop its managed child processes during shutdown)
-            tokio::time::sleep(Duration::from_secs(3)).await;
+
+            // Poll the guardian's liveness until it exits or the grace period
+            // elapses, rather than sleeping a fixed guess — this returns as soon
+            // as the guardian has torn down its managed children, and only
+            // escalates to SIGKILL if it overruns the window.
+            let grace = Duration::from_secs(config.advanced.shutdown_grace_period);
+            let deadline = std::time::Instant::now() + grace;
+            while is_process_alive(guardian_pid) && std::time::Instant::now() < deadline {
+                tokio::time::sleep(Duration::from_millis(100)).await;
+            }
 
             if is_process_alive(guardian_pid) {
-                warn!("Guardian still running, sending SIGKILL");
+                warn!(
+                    "Guardian still running after {}s grace, sending SIGKILL",
+                    grace.as_secs()
+                );
                 unsafe {
                     libc::kill(guardian_pid as i32, libc::SIGKILL);
                 }
@@ -493,6 +506,12 @@ async fn handle_logs(
     follow: bool,
     tail: usize,
 ) -> Result<()> {
+    // When logging to the journal, there is no flat file to tail — defer to
+    // journalctl, which understands rotation and follow natively.
+    if config.logging.backend == LogBackend::Journald {
+        return query_journald(process.as_deref(), follow, tail).await;
+    }
+
     // Determine which log file to read
     let log_file = match process {
         Some(ref name) => {
@@ -530,22 +549,89 @@ async fn handle_logs(
 
     if follow {
         println!("{}", "--- Following log output (Ctrl+C to stop) ---".dimmed());
+        follow_file(path).await?;
+    }
+
+    Ok(())
+}
+
+/// Follow a log file `tail -F` style: resilient to rotation, compression, and
+/// truncation. We track the file's (device, inode) identity plus its size and
+/// seek position; when the inode changes (the file was rotated/replaced) or the
+/// size shrinks below our position (truncated), we reopen from the top of the
+/// new file and reset. Without this, a byte offset into a rotated file goes
+/// stale and output silently stops.
+async fn follow_file(path: &Path) -> Result<()> {
+    use std::io::{Read, Seek, SeekFrom};
+    use std::os::unix::fs::MetadataExt;
 
-        // Simple follow: poll for new content
-        let mut last_len = content.len();
-        loop {
-            tokio::time::sleep(Duration::from_millis(500)).await;
+    // Identity of the file we are currently reading from.
+    let mut file_id: Option<(u64, u64)> = None;
+    let mut pos: u64 = 0;
 
-            if let Ok(new_content) = std::fs::read_to_string(path) {
-                if new_content.len() > last_len {
-                    let new_part = &new_content[last_len..];
-                    print!("{}", new_part);
-                    last_len = new_content.len();
+    loop {
+        match std::fs::File::open(path) {
+            Ok(mut file) => {
+                let meta = file.metadata()?;
+                let id = (meta.dev(), meta.ino());
+
+                match file_id {
+                    // First open: the last lines were already printed, so
+                    // start at the current end.
+                    None => {
+                        file_id = Some(id);
+                        pos = meta.len();
+                    }
+                    // Rotated/replaced: read the new file from the top.
+                    Some(prev) if prev != id => {
+                        info!("Log file rotated, reopening from start");
+                        file_id = Some(id);
+                        pos = 0;
+                    }
+                    // Same file but it shrank: it was truncated in place.
+                    Some(_) if meta.len() < pos => {
+                        pos = 0;
+                    }
+                    Some(_) => {}
+                }
+
+                if meta.len() > pos {
+                    file.seek(SeekFrom::Start(pos))?;
+                    let mut buf = String::new();
+                    file.read_to_string(&mut buf)?;
+                    print!("{}", buf);
+                    pos = meta.len();
                 }
             }
+            Err(_) => {
+                // File momentarily gone during rotation — wait for it to reappear.
+                // The old identity is kept, so the replacement is seen as
+                // rotated and read from the top.
+            }
         }
+
+        tokio::time::sleep(Duration::from_millis(500)).await;
     }
+}
 
+/// Query the system journal via `journalctl` when the journald backend is
+/// active, optionally scoped to a single process and following live output.
+async fn query_journald(process: Option<&str>, follow: bool, tail: usize) -> Result<()> {
+    let mut cmd = tokio::process::Command::new("journalctl");
+    cmd.arg("-t").arg("oc-guardian");
+    cmd.arg("-n").arg(tail.to_string());
+    if let Some(name) = process {
+        // The journald sink doesn't emit a structured per-process field, so
+        // narrow by matching the guardian's own log lines, which quote the
+        // process name (e.g. "Process 'foo' ..."), via journalctl --grep.
+        cmd.arg("-g").arg(format!("'{}'", name));
+    }
+    if follow {
+        cmd.arg("-f");
+    }
+
+    let mut child = cmd.spawn().context("Failed to launch journalctl")?;
+    child.wait().await?;
     Ok(())
 }
 
@@ -588,6 +674,95 @@ fn is_process_alive(pid: u32) -> bool {
     unsafe { libc::kill(pid as i32, 0) == 0 }
 }
 
+// =============================================================================
+// Child Reaping (subreaper support)
+// =============================================================================
+
+/// Reap exited children without blocking.
+///
+/// On Linux we are a subreaper, so orphaned descendants reparent to us and would
+/// otherwise linger as zombies with no one to collect them. We reap ONLY those
+/// true orphans: the managed processes we spawned are tokio `Child` handles that
+/// tokio reaps itself via `waitpid(pid, …)`, and if we consumed their exit
+/// status first with a blanket `waitpid(-1)` their `wait()`/`try_wait()` would
+/// return `ECHILD` and never resolve (breaking stop/rolling-restart and leaving
+/// `last_exit` unreliable). Their deaths are instead picked up by the Level-1
+/// "Process Alive" health check. On platforms without a subreaper we can only
+/// observe the children we spawned directly, so we `try_wait` their owned
+/// `Child` handles instead.
+#[cfg(target_os = "linux")]
+async fn reap_children(manager: &ProcessManager) {
+    // PIDs tokio owns — never reap these here; leave the status for tokio.
+    let managed: std::collections::HashSet<u32> = {
+        let mut set = std::collections::HashSet::new();
+        for (_name, proc_arc) in &manager.processes {
+            let proc = proc_arc.lock().await;
+            if let Some(pid) = proc.pid {
+                set.insert(pid);
+            }
+        }
+        set
+    };
+
+    loop {
+        // Peek at the next ready child WITHOUT consuming its status (WNOWAIT),
+        // so a managed child we decide to skip is still there for tokio.
+        let mut info: libc::siginfo_t = unsafe { std::mem::zeroed() };
+        let rc = unsafe {
+            libc::waitid(
+                libc::P_ALL,
+                0,
+                &mut info,
+                libc::WEXITED | libc::WNOHANG | libc::WNOWAIT,
+            )
+        };
+        if rc != 0 {
+            // -1 with ECHILD (no children) or another error: nothing to do.
+            break;
+        }
+        let pid = unsafe { info.si_pid() } as u32;
+        if pid == 0 {
+            // No exited child is ready.
+            break;
+        }
+        if managed.contains(&pid) {
+            // A managed child is at the head of the queue; leave it for tokio
+            // and stop sweeping (remaining orphans are collected next tick).
+            break;
+        }
+        // A true orphan: consume its status so it doesn't linger as a zombie.
+        let mut status: libc::c_int = 0;
+        let reaped = unsafe { libc::waitpid(pid as i32, &mut status, libc::WNOHANG) };
+        if reaped <= 0 {
+            break;
+        }
+        info!("Reaped orphaned descendant (PID: {})", pid);
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+async fn reap_children(manager: &ProcessManager) {
+    for (name, proc_arc) in &manager.processes {
+        let mut proc = proc_arc.lock().await;
+        if proc.state != ProcessState::Running {
+            continue;
+        }
+        if let Some(ref mut child) = proc.child {
+            if let Ok(Some(status)) = child.try_wait() {
+                info!("Reaped managed process '{}' (status: {})", name, status);
+                proc.last_exit_code = status.code();
+                // The process died while we believed it Running and were not
+                // stopping it — an outside kill or a crash. Classify it as
+                // KilledExternal so the restart policy treats it as warranting
+                // a restart (an intentional stop sets ChildExit::Killed).
+                proc.last_exit = Some(crate::process::ChildExit::KilledExternal);
+                proc.state = ProcessState::Failed;
+                proc.child = None;
+            }
+        }
+    }
+}
+
 // =============================================================================
 // Supervisor Main Loop (Phase 3: with log rotation, compression, sleep, notifications)
 // =============================================================================
@@ -597,6 +772,9 @@ async fn supervisor_loop(
     manager: ProcessManager,
     running: Arc<Mutex<bool>>,
 ) -> Result<()> {
+    // Shared so per-process pidfd death-watchers can be spawned onto tasks that
+    // outlive a single loop iteration; `&manager` call sites deref-coerce.
+    let manager = Arc::new(manager);
     let interval = Duration::from_secs(config.advanced.supervisor_interval);
     let mut health_checker = HealthChecker::new();
     let mut recovery_engine = RecoveryEngine::new(config.recovery.clone());
@@ -607,6 +785,22 @@ async fn supervisor_loop(
         CompressionManager::new(config.memory.compression.clone());
     let mut notifier = NotificationManager::new(config.notifications.clone());
 
+    // Become an init-style subreaper so descendants orphaned by managed
+    // processes (openclaw/oc-memory helper forks) reparent to us instead of
+    // PID 1 — otherwise they accumulate as zombies with no one to reap them.
+    #[cfg(target_os = "linux")]
+    {
+        let rc = unsafe { libc::prctl(libc::PR_SET_CHILD_SUBREAPER, 1, 0, 0, 0) };
+        if rc == 0 {
+            info!("Registered as child subreaper (PR_SET_CHILD_SUBREAPER)");
+        } else {
+            warn!(
+                "Failed to set child subreaper: {}",
+                std::io::Error::last_os_error()
+            );
+        }
+    }
+
     info!("Supervisor loop started (interval: {:?})", interval);
     println!(
         "{}",
@@ -669,6 +863,12 @@ async fn supervisor_loop(
 
         check_count += 1;
 
+        // Reap any children that have exited since the last tick. On Linux, as
+        // a subreaper, this collects the zombie orphans we inherit (managed
+        // children themselves are left to tokio); elsewhere it try_waits the
+        // managed children's own handles and marks any that exited as Failed.
+        reap_children(&manager).await;
+
         // Health check each process
         for (name, proc_arc) in &manager.processes {
             let proc = proc_arc.lock().await;
@@ -676,6 +876,16 @@ async fn supervisor_loop(
             if proc.state != ProcessState::Running {
                 // Check if process was supposed to be running but died
                 if proc.state == ProcessState::Failed && proc.config.auto_restart {
+                    // Only revive crashes / external kills / signals — never a
+                    // process we intentionally stopped (ChildExit::Killed).
+                    let warrants_restart = proc
+                        .last_exit
+                        .as_ref()
+                        .map(crate::process::ChildExit::warrants_restart)
+                        .unwrap_or(true);
+                    if !warrants_restart {
+                        continue;
+                    }
                     let restart_count = proc.restart_count;
                     drop(proc);
 
@@ -816,7 +1026,40 @@ async fn supervisor_loop(
             }
         }
 
-        tokio::time::sleep(interval).await;
+        // Sleep until the next supervisor tick, but wake the instant a managed
+        // process dies so recovery runs immediately instead of up to `interval`
+        // later. Each running managed process is watched via its pidfd;
+        // `watch_exit` degrades to a plain `interval` sleep where pidfd is
+        // unavailable, so this is a pure speedup on Linux and a no-op elsewhere.
+        let mut watchers = tokio::task::JoinSet::new();
+        for (name, proc_arc) in &manager.processes {
+            let watch = {
+                let proc = proc_arc.lock().await;
+                proc.state == ProcessState::Running && proc.config.managed
+            };
+            if watch {
+                let mgr = manager.clone();
+                let name = name.clone();
+                watchers.spawn(async move { mgr.watch_exit(&name, interval).await.then_some(name) });
+            }
+        }
+
+        if watchers.is_empty() {
+            tokio::time::sleep(interval).await;
+        } else {
+            tokio::select! {
+                _ = tokio::time::sleep(interval) => {}
+                Some(joined) = watchers.join_next() => {
+                    if let Ok(Some(name)) = joined {
+                        info!(
+                            "Managed process '{}' exited (pidfd); running recovery now",
+                            name
+                        );
+                        manager.mark_external_exit(&name).await;
+                    }
+                }
+            }
+        }
     }
 
     Ok(())
@@ -0,0 +1,78 @@
+//! Event-driven process-death notification.
+//!
+//! On Linux 5.3+ a `pidfd` becomes readable the instant the referenced process
+//! exits, so registering it with tokio's [`AsyncFd`] lets the manager react to
+//! a crash immediately instead of waiting out a `sysinfo` poll interval. On
+//! other platforms — or on kernels without `pidfd_open(2)` — callers fall back
+//! to the existing polling loop; [`DeathNotifier::open`] returns `None` in that
+//! case so the caller can degrade gracefully.
+
+use std::time::Duration;
+use tracing::debug;
+
+#[cfg(target_os = "linux")]
+use std::os::unix::io::{FromRawFd, OwnedFd, RawFd};
+#[cfg(target_os = "linux")]
+use tokio::io::unix::AsyncFd;
+
+/// A readiness watcher over a process's `pidfd`.
+#[cfg(target_os = "linux")]
+pub struct DeathNotifier {
+    inner: AsyncFd<OwnedFd>,
+}
+
+#[cfg(target_os = "linux")]
+impl DeathNotifier {
+    /// Open a `pidfd` for an already-running PID (e.g. a discovered external
+    /// process). Returns `None` when the kernel lacks `pidfd_open` (`ENOSYS`)
+    /// or rejects the call (`EINVAL`), signalling the caller to poll instead.
+    pub fn open(pid: u32) -> Option<Self> {
+        // pidfd_open is syscall 434 on all Linux architectures.
+        const SYS_PIDFD_OPEN: libc::c_long = 434;
+        let fd = unsafe { libc::syscall(SYS_PIDFD_OPEN, pid as libc::pid_t, 0) };
+        if fd < 0 {
+            let err = std::io::Error::last_os_error();
+            debug!("pidfd_open({}) failed: {}; falling back to polling", pid, err);
+            return None;
+        }
+        Self::from_raw(fd as RawFd)
+    }
+
+    /// Wrap a `pidfd` already owned by the caller (e.g. tokio's `Child::id`
+    /// paired with a freshly opened fd on spawn).
+    pub fn from_raw(fd: RawFd) -> Option<Self> {
+        // SAFETY: `fd` is a valid, owned pidfd we take responsibility for.
+        let owned = unsafe { OwnedFd::from_raw_fd(fd) };
+        match AsyncFd::new(owned) {
+            Ok(inner) => Some(Self { inner }),
+            Err(e) => {
+                debug!("failed to register pidfd with tokio: {}", e);
+                None
+            }
+        }
+    }
+
+    /// Await process exit, up to `timeout`. Returns `true` if the process
+    /// exited (the pidfd became readable), `false` on timeout.
+    pub async fn wait_for_exit(&self, timeout: Duration) -> bool {
+        matches!(
+            tokio::time::timeout(timeout, self.inner.readable()).await,
+            Ok(Ok(_))
+        )
+    }
+}
+
+/// Non-Linux stub: no pidfd support, callers poll.
+#[cfg(not(target_os = "linux"))]
+pub struct DeathNotifier;
+
+#[cfg(not(target_os = "linux"))]
+impl DeathNotifier {
+    pub fn open(_pid: u32) -> Option<Self> {
+        None
+    }
+
+    pub async fn wait_for_exit(&self, _timeout: Duration) -> bool {
+        false
+    }
+}
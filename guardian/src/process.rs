@@ -10,7 +10,9 @@ use tokio::process::{Child, Command};
 use tokio::sync::Mutex;
 use tracing::{error, info, warn};
 
+use crate::capture::{self, OutputCapture};
 use crate::config::{GuardianConfig, ProcessConfig, ReadyMethod};
+use crate::socket::SocketRegistry;
 
 // =============================================================================
 // Process State
@@ -37,6 +39,49 @@ impl std::fmt::Display for ProcessState {
     }
 }
 
+// =============================================================================
+// Exit Classification
+// =============================================================================
+
+/// How a managed process's most recent run ended.
+///
+/// Recording *why* a process is gone lets restart policy distinguish an
+/// intentional stop (don't restart) from a crash or an outside `kill` (do
+/// restart). Modeled on turborepo's `ChildExit`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChildExit {
+    /// Exited on its own with this exit code (`None` if unavailable).
+    Finished(Option<i32>),
+    /// We sent the stop signal ourselves — an intentional shutdown.
+    Killed,
+    /// Died while we believed it was `Running` and were not stopping it
+    /// (an outside `kill`, OOM, etc.).
+    KilledExternal,
+    /// Terminated by a signal, captured from the wait status.
+    Signaled(i32),
+}
+
+impl std::fmt::Display for ChildExit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChildExit::Finished(Some(code)) => write!(f, "finished (code {})", code),
+            ChildExit::Finished(None) => write!(f, "finished"),
+            ChildExit::Killed => write!(f, "killed (by guardian)"),
+            ChildExit::KilledExternal => write!(f, "killed externally"),
+            ChildExit::Signaled(sig) => write!(f, "signaled ({})", sig),
+        }
+    }
+}
+
+impl ChildExit {
+    /// Whether an exit of this kind should trigger the restart policy. An
+    /// intentional guardian stop ([`ChildExit::Killed`]) never does; a crash,
+    /// a termination signal, or an outside kill all do.
+    pub fn warrants_restart(&self) -> bool {
+        !matches!(self, ChildExit::Killed)
+    }
+}
+
 // =============================================================================
 // Managed Process
 // =============================================================================
@@ -47,11 +92,23 @@ pub struct ManagedProcess {
     pub config: ProcessConfig,
     pub state: ProcessState,
     pub pid: Option<u32>,
+    /// Process-group id of the spawned child. On Unix a managed process is made
+    /// a process-group leader (its pgid equals its pid) unless it sets
+    /// `process_group = false`, so shutdown can signal the whole tree with
+    /// `kill(-pgid, ...)` instead of name-matching. `None` when the process
+    /// opted out, and for externally managed (managed=false) processes we
+    /// didn't spawn.
+    pub pgid: Option<u32>,
     pub child: Option<Child>,
     pub started_at: Option<DateTime<Utc>>,
     pub restart_count: u32,
     pub last_exit_code: Option<i32>,
+    /// Classification of how the last run ended (crash vs intentional stop vs
+    /// external kill), used to make restart decisions precise.
+    pub last_exit: Option<ChildExit>,
     pub restart_timestamps: Vec<Instant>,
+    /// Live capture of the process's stdout/stderr (ring buffer + broadcast).
+    pub capture: Option<Arc<OutputCapture>>,
 }
 
 impl ManagedProcess {
@@ -61,11 +118,14 @@ impl ManagedProcess {
             config,
             state: ProcessState::Stopped,
             pid: None,
+            pgid: None,
             child: None,
             started_at: None,
             restart_count: 0,
             last_exit_code: None,
+            last_exit: None,
             restart_timestamps: Vec::new(),
+            capture: None,
         }
     }
 
@@ -104,6 +164,9 @@ impl ManagedProcess {
 pub struct ProcessManager {
     pub processes: HashMap<String, Arc<Mutex<ManagedProcess>>>,
     config: GuardianConfig,
+    /// Listeners the guardian binds on behalf of socket-activated processes and
+    /// keeps open across restarts for zero-downtime rollovers.
+    sockets: Arc<Mutex<SocketRegistry>>,
 }
 
 impl ProcessManager {
@@ -120,7 +183,11 @@ impl ProcessManager {
             );
         }
 
-        Self { processes, config }
+        Self {
+            processes,
+            config,
+            sockets: Arc::new(Mutex::new(SocketRegistry::new())),
+        }
     }
 
     /// Start a single process by name
@@ -160,16 +227,65 @@ impl ProcessManager {
             cmd.env(key, value);
         }
 
-        // Redirect stdout/stderr to null to prevent pipe buffer deadlock.
-        // Process output is captured via log files configured in health checks.
-        cmd.stdout(std::process::Stdio::null());
-        cmd.stderr(std::process::Stdio::null());
+        // Pipe stdout/stderr and drain them asynchronously (see `capture`). The
+        // drain tasks prevent the pipe-buffer deadlock the old `/dev/null`
+        // redirect guarded against, while keeping the bytes for live readiness
+        // matching and `tail`.
+        cmd.stdout(std::process::Stdio::piped());
+        cmd.stderr(std::process::Stdio::piped());
+
+        // Make the child a new process-group leader so we can signal the whole
+        // tree (the process plus any shells/grandchildren it spawns) on stop,
+        // instead of scanning sysinfo for command-name matches. The new group's
+        // pgid equals the child's pid. Single-process daemons can opt out with
+        // `process_group = false`.
+        #[cfg(unix)]
+        if proc.config.process_group {
+            cmd.process_group(0);
+        }
+
+        // On Windows there are no process groups; put the child in its own
+        // console process group so a CTRL_BREAK reaches the whole tree. A Job
+        // Object assignment would give the same kill-the-subtree guarantee as
+        // the Unix pgid, but the guardian only targets macOS/Linux today.
+        #[cfg(windows)]
+        if proc.config.process_group {
+            use std::os::windows::process::CommandExt;
+            const CREATE_NEW_PROCESS_GROUP: u32 = 0x0000_0200;
+            cmd.creation_flags(CREATE_NEW_PROCESS_GROUP);
+        }
+
+        // Hand the child any activation sockets the guardian owns for it. The
+        // listeners are bound once and reused across restarts, so the socket is
+        // never closed between instances.
+        if !proc.config.sockets.is_empty() {
+            let mut registry = self.sockets.lock().await;
+            let set = registry.register(name, &proc.config.sockets)?;
+            set.inherit_into(&mut cmd);
+        }
 
         // Spawn
         match cmd.spawn() {
-            Ok(child) => {
+            Ok(mut child) => {
+                // Wire up async output capture from the piped streams.
+                let capture = OutputCapture::new();
+                capture::attach(&capture, child.stdout.take(), child.stderr.take());
+                proc.capture = Some(capture);
+
                 let pid = child.id();
                 proc.pid = pid;
+                #[cfg(unix)]
+                {
+                    // Only record a process-group id when we actually made the
+                    // child a group leader (`process_group(0)` above). Otherwise
+                    // `kill(-pid, …)` at stop time would target a non-existent
+                    // group (ESRCH) and the stop would silently no-op; leaving
+                    // `pgid` as `None` makes stop fall back to signalling the
+                    // child pid directly.
+                    if proc.config.process_group {
+                        proc.pgid = pid;
+                    }
+                }
                 proc.child = Some(child);
                 proc.state = ProcessState::Running;
                 proc.started_at = Some(Utc::now());
@@ -275,36 +391,21 @@ impl ProcessManager {
         proc.state = ProcessState::Stopping;
         info!("Stopping process '{}'...", name);
 
+        let pgid = proc.pgid;
+        // Build the escalation sequence before borrowing `child`. A terminal
+        // SIGKILL is appended by `terminate_with_grace`.
+        let sequence = self.build_stop_sequence(&proc, grace_period);
         if let Some(ref mut child) = proc.child {
-            let grace = Duration::from_secs(
-                grace_period.unwrap_or(self.config.advanced.shutdown_grace_period),
-            );
-
-            // Phase 1: Send kill signal (SIGTERM on Unix, TerminateProcess on Windows)
-            info!("Sending terminate to process '{}'", name);
-            let _ = child.start_kill();
-
-            // Phase 2: Wait for process to exit within grace period
-            match tokio::time::timeout(grace, child.wait()).await {
-                Ok(Ok(status)) => {
+            match terminate_with_grace(name, child, pgid, &sequence).await {
+                Some(status) => {
                     proc.last_exit_code = status.code();
-                    info!(
-                        "Process '{}' stopped gracefully with status: {}",
-                        name, status
-                    );
+                    // We initiated this stop, so classify as Killed (see
+                    // `exit_from_status`).
+                    proc.last_exit = Some(exit_from_status(&status, true));
+                    info!("Process '{}' stopped with status: {}", name, status);
                 }
-                Ok(Err(e)) => {
-                    warn!("Error waiting for process '{}': {}", name, e);
-                }
-                Err(_) => {
-                    // Phase 3: Grace period expired - force kill
-                    warn!(
-                        "Process '{}' did not stop within {}s grace period, force killing",
-                        name,
-                        grace.as_secs()
-                    );
-                    let _ = child.kill().await;
-                    let _ = child.wait().await;
+                None => {
+                    proc.last_exit = Some(ChildExit::Killed);
                     info!("Process '{}' force killed", name);
                 }
             }
@@ -312,16 +413,58 @@ impl ProcessManager {
 
         proc.state = ProcessState::Stopped;
         proc.pid = None;
+        proc.pgid = None;
         proc.child = None;
+        proc.capture = None;
 
         info!("Process '{}' stopped", name);
         Ok(())
     }
 
+    /// Build the signal-escalation sequence for stopping `proc`: `(signal,
+    /// grace-seconds)` pairs tried in order before the terminal `SIGKILL` that
+    /// [`terminate_with_grace`] always appends. Honours an explicit
+    /// `stop_signals` list when present, otherwise falls back to a single
+    /// `SIGTERM` with the per-process `shutdown_timeout` (or the global grace
+    /// period).
+    fn build_stop_sequence(
+        &self,
+        proc: &ManagedProcess,
+        grace_period: Option<u64>,
+    ) -> Vec<(i32, u64)> {
+        if proc.config.stop_signals.is_empty() {
+            let default_grace = grace_period.unwrap_or_else(|| {
+                proc.config
+                    .shutdown_timeout
+                    .unwrap_or(self.config.advanced.shutdown_grace_period)
+            });
+            vec![(libc::SIGTERM, default_grace)]
+        } else {
+            proc.config
+                .stop_signals
+                .iter()
+                .map(|(sig, secs)| (sig.to_libc(), *secs))
+                .collect()
+        }
+    }
+
     /// Restart a single process
     pub async fn restart_process(&self, name: &str) -> Result<()> {
         info!("Restarting process '{}'...", name);
 
+        // Socket-activated processes get a zero-downtime rolling restart: the
+        // replacement comes up on the guardian-owned listeners and passes its
+        // readiness check *before* the old instance is signalled, so the socket
+        // stays open and connections are never refused.
+        let rolling = {
+            let proc_arc = self.processes.get(name).unwrap().clone();
+            let proc = proc_arc.lock().await;
+            proc.config.managed && !proc.config.sockets.is_empty()
+        };
+        if rolling {
+            return self.rolling_restart(name).await;
+        }
+
         self.stop_process(name).await?;
 
         // Apply restart delay
@@ -340,6 +483,69 @@ impl ProcessManager {
         Ok(())
     }
 
+    /// Connection-preserving restart for socket-activated processes.
+    ///
+    /// Spawns the replacement while the old instance keeps serving on the shared
+    /// listeners, waits for the new instance's readiness check, and only then
+    /// terminates the old process group. The listeners themselves are owned by
+    /// the guardian (via [`SocketRegistry`]) and are never closed.
+    async fn rolling_restart(&self, name: &str) -> Result<()> {
+        let proc_arc = self.processes.get(name).unwrap().clone();
+
+        // Detach the old instance: take its child/pgid but keep the handle alive
+        // in a local so the process keeps running during the overlap window.
+        let (old_child, old_pgid, ready_cfg, stop_sequence) = {
+            let mut proc = proc_arc.lock().await;
+            // Capture the stop sequence now, while the old instance's config is
+            // still in hand, so retiring it later uses the same staged
+            // escalation as a normal stop.
+            let stop_sequence = self.build_stop_sequence(&proc, None);
+            (
+                proc.child.take(),
+                proc.pgid.take(),
+                proc.config.ready.clone(),
+                stop_sequence,
+            )
+        };
+
+        // Reset tracking so start_process spawns a fresh instance into the slot.
+        {
+            let mut proc = proc_arc.lock().await;
+            proc.state = ProcessState::Stopped;
+            proc.pid = None;
+        }
+
+        // Bring up the replacement on the same inherited listeners, then wait
+        // for it to pass readiness. On *either* failure we must retire the old
+        // group before returning: dropping its `Child` does not kill it
+        // (`kill_on_drop` defaults to false), so it would keep running untracked
+        // and hold the inherited listener alongside the new instance.
+        let bring_up = async {
+            self.start_process(name).await?;
+            self.wait_for_ready(name, &ready_cfg).await
+        };
+        if let Err(e) = bring_up.await {
+            warn!(
+                "Rolling restart of '{}' failed to become ready; retiring old instance",
+                name
+            );
+            retire_old_instance(name, old_child, old_pgid, &stop_sequence).await;
+            return Err(e);
+        }
+
+        // Replacement is serving — retire the old process group.
+        info!("Rolling restart of '{}' ready; retiring old instance", name);
+        retire_old_instance(name, old_child, old_pgid, &stop_sequence).await;
+
+        {
+            let mut proc = proc_arc.lock().await;
+            proc.restart_count += 1;
+            proc.restart_timestamps.push(Instant::now());
+        }
+
+        Ok(())
+    }
+
     /// Start all processes in dependency order
     pub async fn start_all(&self) -> Result<()> {
         self.start_all_with_flag(None).await
@@ -428,7 +634,21 @@ impl ProcessManager {
                 return Ok(()); // Don't fail — the process may start later
             }
 
-            tokio::time::sleep(Duration::from_secs(5)).await;
+            // Wait 5s before rescanning: on the pidfd of a recorded pid (waking
+            // early if it exits), else a plain sleep. Discovery records a pid
+            // only when it finds the process, which returns above, so in this
+            // loop no pid is recorded and the wait is a plain 5s sleep.
+            let pid = {
+                let proc_arc = self.processes.get(name).unwrap().clone();
+                let proc = proc_arc.lock().await;
+                proc.pid
+            };
+            match pid.and_then(crate::pidfd::DeathNotifier::open) {
+                Some(notifier) => {
+                    notifier.wait_for_exit(Duration::from_secs(5)).await;
+                }
+                None => tokio::time::sleep(Duration::from_secs(5)).await,
+            }
         }
     }
 
@@ -501,41 +721,61 @@ impl ProcessManager {
             Regex::new(pattern).with_context(|| format!("Invalid regex pattern: {}", pattern))?;
         let start = Instant::now();
 
-        // Get log file path from process health config
-        let log_file = {
+        // Prefer the live capture of the process's own stdout/stderr.
+        let (capture, log_file) = {
             let proc_arc = self.processes.get(name).unwrap().clone();
             let proc = proc_arc.lock().await;
-            proc.config.health.log_file.clone()
+            (proc.capture.clone(), proc.config.health.log_file.clone())
         };
 
+        if let Some(capture) = capture {
+            // Subscribe first so we don't miss lines emitted between the backlog
+            // scan and the await, then check already-captured lines.
+            let mut rx = capture.subscribe();
+            if capture.tail(usize::MAX).iter().any(|l| regex.is_match(l)) {
+                return Ok(());
+            }
+
+            while start.elapsed() < timeout {
+                let remaining = timeout.saturating_sub(start.elapsed());
+                match tokio::time::timeout(remaining, rx.recv()).await {
+                    Ok(Ok(line)) => {
+                        if regex.is_match(&line) {
+                            return Ok(());
+                        }
+                    }
+                    // Lagged: we fell behind the ring; rescan the backlog.
+                    Ok(Err(tokio::sync::broadcast::error::RecvError::Lagged(_))) => {
+                        if capture.tail(usize::MAX).iter().any(|l| regex.is_match(l)) {
+                            return Ok(());
+                        }
+                    }
+                    // Timed out, or the channel closed. The channel stays open
+                    // after the process exits, since `capture` holds the sender.
+                    Ok(Err(_)) | Err(_) => break,
+                }
+            }
+            return Ok(());
+        }
+
+        // No capture (e.g. externally managed process) — watch its log file.
         if let Some(log_path) = log_file {
             let path = Path::new(&log_path);
 
-            // Wait for log file to appear
             while !path.exists() && start.elapsed() < timeout {
                 tokio::time::sleep(Duration::from_millis(200)).await;
             }
 
-            if path.exists() {
-                // Read log file and check for pattern
-                while start.elapsed() < timeout {
-                    if let Ok(content) = tokio::fs::read_to_string(path).await {
-                        if regex.is_match(&content) {
-                            return Ok(());
-                        }
+            while path.exists() && start.elapsed() < timeout {
+                if let Ok(content) = tokio::fs::read_to_string(path).await {
+                    if regex.is_match(&content) {
+                        return Ok(());
                     }
-                    tokio::time::sleep(Duration::from_millis(500)).await;
                 }
+                tokio::time::sleep(Duration::from_millis(500)).await;
             }
         }
 
-        // Also check stdout of the process
-        // For simplicity in this implementation, we fall back to a time-based wait
-        // if the log file approach times out
-        if start.elapsed() < timeout {
-            tokio::time::sleep(Duration::from_secs(3)).await;
-        }
-
         Ok(())
     }
 
@@ -560,6 +800,67 @@ impl ProcessManager {
         )
     }
 
+    /// Await the event-driven exit of a managed process via its `pidfd`,
+    /// returning `true` if it exited within `timeout`. When no pidfd can be
+    /// opened (non-Linux / old kernel) this sleeps out the `timeout` and then
+    /// returns `false`, so a caller racing many watchers against a tick doesn't
+    /// busy-spin; it then falls back to the periodic health-poll path.
+    pub async fn watch_exit(&self, name: &str, timeout: Duration) -> bool {
+        let pid = match self.processes.get(name) {
+            Some(proc_arc) => proc_arc.lock().await.pid,
+            None => return false,
+        };
+        match pid.and_then(crate::pidfd::DeathNotifier::open) {
+            Some(notifier) => notifier.wait_for_exit(timeout).await,
+            None => {
+                tokio::time::sleep(timeout).await;
+                false
+            }
+        }
+    }
+
+    /// Record that a managed process died unexpectedly while we believed it was
+    /// `Running` and were not stopping it (observed via its pidfd — an outside
+    /// `kill`, a crash, OOM, …). Reaps the child handle so it can't linger as a
+    /// zombie, classifies the exit as [`ChildExit::KilledExternal`], and moves
+    /// the process to `Failed` so the supervisor's restart policy picks it up.
+    /// A no-op if the process is no longer `Running` (e.g. a guardian stop began
+    /// in the meantime), so it never overrides an intentional shutdown.
+    pub async fn mark_external_exit(&self, name: &str) {
+        let proc_arc = match self.processes.get(name) {
+            Some(proc_arc) => proc_arc.clone(),
+            None => return,
+        };
+        let mut proc = proc_arc.lock().await;
+        if proc.state != ProcessState::Running {
+            return;
+        }
+        if let Some(mut child) = proc.child.take() {
+            if let Ok(Some(status)) = child.try_wait() {
+                proc.last_exit_code = status.code();
+            }
+        }
+        proc.last_exit = Some(ChildExit::KilledExternal);
+        proc.state = ProcessState::Failed;
+        proc.pid = None;
+        warn!("Process '{}' exited unexpectedly (external kill or crash)", name);
+    }
+
+    /// Return the last `lines` captured stdout/stderr lines for a process,
+    /// oldest first. Empty when the process has no active capture.
+    pub async fn tail(&self, name: &str, lines: usize) -> Vec<String> {
+        match self.processes.get(name) {
+            Some(proc_arc) => {
+                let proc = proc_arc.lock().await;
+                proc.capture
+                    .as_ref()
+                    .map(|c| c.tail(lines))
+                    .unwrap_or_default()
+            }
+            None => Vec::new(),
+        }
+    }
+
     /// Check if a process is still running
     pub async fn is_running(&self, name: &str) -> bool {
         if let Some(proc_arc) = self.processes.get(name) {
@@ -582,6 +883,7 @@ impl ProcessManager {
                 pid: proc.pid,
                 uptime: proc.uptime_display(),
                 restart_count: proc.restart_count,
+                last_exit: proc.last_exit.clone(),
             });
         }
 
@@ -611,6 +913,119 @@ pub struct ProcessStatus {
     pub pid: Option<u32>,
     pub uptime: String,
     pub restart_count: u32,
+    /// How the process last exited, if it has exited at least once.
+    pub last_exit: Option<ChildExit>,
+}
+
+/// Classify an `ExitStatus` into a [`ChildExit`].
+///
+/// When `we_stopped` is true the exit was requested by the guardian: the
+/// process almost always dies from the `SIGTERM`/`SIGKILL` *we* sent, so it is
+/// classified as [`ChildExit::Killed`] regardless of whether it exited or was
+/// signaled — otherwise an intentional stop would look like an external
+/// `Signaled` exit and a restart policy could wrongly revive it. When we did
+/// not stop it, a termination signal maps to [`ChildExit::Signaled`] and a
+/// plain exit to [`ChildExit::Finished`], captured via `ExitStatus::signal()`
+/// on Unix.
+pub fn exit_from_status(status: &std::process::ExitStatus, we_stopped: bool) -> ChildExit {
+    if we_stopped {
+        return ChildExit::Killed;
+    }
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::ExitStatusExt;
+        if let Some(sig) = status.signal() {
+            return ChildExit::Signaled(sig);
+        }
+    }
+    ChildExit::Finished(status.code())
+}
+
+/// Drive a managed child through a staged stop and reap it.
+///
+/// For each `(signal, grace-seconds)` in `sequence`, signals the process group
+/// (if `pgid` is set, else the child directly) and waits up to the grace slice
+/// for it to exit, escalating to the next entry on timeout. When the sequence
+/// is exhausted a terminal `SIGKILL` guarantees the process is gone. Returns
+/// the reaped `ExitStatus` when a graceful signal took effect, or `None` when
+/// the terminal `SIGKILL` was required (or the wait errored).
+async fn terminate_with_grace(
+    name: &str,
+    child: &mut tokio::process::Child,
+    pgid: Option<u32>,
+    sequence: &[(i32, u64)],
+) -> Option<std::process::ExitStatus> {
+    for &(signal, secs) in sequence {
+        info!("Sending signal {} to process '{}'", signal, name);
+        match pgid {
+            // Signal the whole group so detached children die too.
+            Some(pgid) => signal_group(pgid, signal),
+            // No group: signal the child's own pid (gone once it is reaped).
+            None => {
+                if let Some(pid) = child.id() {
+                    unsafe {
+                        libc::kill(pid as i32, signal);
+                    }
+                }
+            }
+        }
+
+        match tokio::time::timeout(Duration::from_secs(secs), child.wait()).await {
+            Ok(Ok(status)) => return Some(status),
+            Ok(Err(e)) => {
+                warn!("Error waiting for process '{}': {}", name, e);
+                return None;
+            }
+            // Grace slice expired — escalate to the next signal.
+            Err(_) => {
+                warn!(
+                    "Process '{}' survived signal {} after {}s, escalating",
+                    name, signal, secs
+                );
+            }
+        }
+    }
+
+    // Terminal step: guarantee the process is gone with SIGKILL.
+    warn!("Process '{}' did not exit, force killing (SIGKILL)", name);
+    match pgid {
+        Some(pgid) => signal_group(pgid, libc::SIGKILL),
+        None => {
+            let _ = child.kill().await;
+        }
+    }
+    let _ = child.wait().await;
+    None
+}
+
+/// Terminate and reap the retired instance of a rolling restart.
+///
+/// Runs the same staged escalation as a normal stop ([`terminate_with_grace`]):
+/// the configured stop signals with bounded grace slices, then a guaranteed
+/// `SIGKILL`. A bare `drop` of the `Child` would leave it running, since
+/// `kill_on_drop` defaults to false, and an unbounded `wait` would hang the
+/// rolling restart forever if the old instance ignores `SIGTERM` — the bounded
+/// escalation avoids both.
+async fn retire_old_instance(
+    name: &str,
+    old_child: Option<tokio::process::Child>,
+    old_pgid: Option<u32>,
+    sequence: &[(i32, u64)],
+) {
+    if let Some(mut child) = old_child {
+        terminate_with_grace(name, &mut child, old_pgid, sequence).await;
+    }
+}
+
+/// Send `signal` to an entire process group (`kill(-pgid, signal)`).
+/// Used for shutting down a managed process together with any children it
+/// spawned; the group was established at spawn time via `process_group(0)`.
+#[cfg(unix)]
+pub fn signal_group(pgid: u32, signal: i32) {
+    // Negating the pgid targets every member of the group.
+    unsafe {
+        libc::kill(-(pgid as i32), signal);
+    }
 }
 
 /// Match a sysinfo Process against a command name and optional args.
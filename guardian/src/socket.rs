@@ -0,0 +1,175 @@
+use anyhow::{Context, Result};
+use std::collections::HashMap;
+use std::net::TcpListener;
+#[cfg(unix)]
+use std::os::unix::io::{AsRawFd, RawFd};
+#[cfg(unix)]
+use std::os::unix::net::UnixListener;
+use tracing::info;
+
+use crate::config::{SocketConfig, SocketKind};
+
+// =============================================================================
+// Socket Activation (Sprint 4: zero-downtime rolling restarts)
+// =============================================================================
+
+/// Listeners the guardian owns on behalf of a managed process.
+///
+/// The guardian binds the sockets declared under `[processes.x.sockets]` once
+/// and keeps the file descriptors open for the lifetime of the process. They
+/// are handed to each spawned instance via inherited FDs plus a `LISTEN_FDS`
+/// environment variable, so a rolling restart can bring up the replacement on
+/// the *same* listener before the old instance is signalled — the socket is
+/// never closed and in-flight connections are never refused.
+///
+/// This is an OC-internal convention, not the full systemd socket-activation
+/// protocol: we pass `LISTEN_FDS` and the fixed descriptor range `3..` but do
+/// NOT set `LISTEN_PID` (see [`SocketSet::inherit_into`] for why), so a child
+/// must trust the descriptors directly rather than validating them through a
+/// stock `sd_listen_fds()`, which returns 0 when `LISTEN_PID` is unset.
+#[derive(Debug, Default)]
+pub struct SocketSet {
+    listeners: Vec<Listener>,
+}
+
+#[derive(Debug)]
+enum Listener {
+    Tcp(TcpListener),
+    #[cfg(unix)]
+    Unix(UnixListener),
+}
+
+impl Listener {
+    #[cfg(unix)]
+    fn raw_fd(&self) -> RawFd {
+        match self {
+            Listener::Tcp(l) => l.as_raw_fd(),
+            Listener::Unix(l) => l.as_raw_fd(),
+        }
+    }
+}
+
+impl SocketSet {
+    /// Bind every socket declared for a process. The bound listeners are held
+    /// open until the returned set is dropped, so they survive across restarts.
+    pub fn bind(configs: &[SocketConfig]) -> Result<Self> {
+        let mut listeners = Vec::with_capacity(configs.len());
+
+        for socket in configs {
+            let listener = match socket.kind {
+                SocketKind::Tcp => {
+                    let l = TcpListener::bind(&socket.address)
+                        .with_context(|| format!("Failed to bind TCP socket {}", socket.address))?;
+                    info!("Bound TCP listener on {}", socket.address);
+                    Listener::Tcp(l)
+                }
+                #[cfg(unix)]
+                SocketKind::Unix => {
+                    // Remove a stale socket file from a previous run before binding.
+                    let _ = std::fs::remove_file(&socket.address);
+                    let l = UnixListener::bind(&socket.address).with_context(|| {
+                        format!("Failed to bind Unix socket {}", socket.address)
+                    })?;
+                    info!("Bound Unix listener on {}", socket.address);
+                    Listener::Unix(l)
+                }
+                #[cfg(not(unix))]
+                SocketKind::Unix => {
+                    anyhow::bail!("Unix domain sockets are not supported on this platform");
+                }
+            };
+            listeners.push(listener);
+        }
+
+        Ok(Self { listeners })
+    }
+
+    /// Whether this set holds any listeners.
+    pub fn is_empty(&self) -> bool {
+        self.listeners.is_empty()
+    }
+
+    /// Inject the inherited listeners into a child command.
+    ///
+    /// Sets `LISTEN_FDS` to the listener count and installs a `pre_exec` hook
+    /// that clears `FD_CLOEXEC` and renumbers each listener to the contiguous
+    /// range starting at fd 3 (`SD_LISTEN_FDS_START`).
+    ///
+    /// We do NOT set `LISTEN_PID`. `std::process::Command` builds the child's
+    /// environment block in the parent before `fork`, so a `setenv` from inside
+    /// `pre_exec` mutates the post-fork `environ` that `execvp` never reads — it
+    /// would never reach the child (and it is not async-signal-safe). The child
+    /// therefore trusts `LISTEN_FDS` and the fixed fd range `3..3+LISTEN_FDS`
+    /// directly. We still strip any `LISTEN_PID` the guardian inherited, so a
+    /// stale value pointing at the guardian's pid can't make a convention-strict
+    /// consumer reject the descriptors.
+    #[cfg(unix)]
+    pub fn inherit_into(&self, cmd: &mut tokio::process::Command) {
+        use std::os::unix::process::CommandExt;
+
+        if self.listeners.is_empty() {
+            return;
+        }
+
+        const LISTEN_FDS_START: RawFd = 3;
+        let fds: Vec<RawFd> = self.listeners.iter().map(|l| l.raw_fd()).collect();
+        let count = fds.len();
+
+        cmd.env("LISTEN_FDS", count.to_string());
+        cmd.env_remove("LISTEN_PID");
+
+        unsafe {
+            cmd.pre_exec(move || {
+                for (offset, &fd) in fds.iter().enumerate() {
+                    let target = LISTEN_FDS_START + offset as RawFd;
+                    if fd != target && libc::dup2(fd, target) < 0 {
+                        return Err(std::io::Error::last_os_error());
+                    }
+                    // Clear close-on-exec so the listener survives the exec.
+                    let flags = libc::fcntl(target, libc::F_GETFD);
+                    if flags < 0 {
+                        return Err(std::io::Error::last_os_error());
+                    }
+                    if libc::fcntl(target, libc::F_SETFD, flags & !libc::FD_CLOEXEC) < 0 {
+                        return Err(std::io::Error::last_os_error());
+                    }
+                }
+                Ok(())
+            });
+        }
+    }
+
+    #[cfg(not(unix))]
+    pub fn inherit_into(&self, _cmd: &mut tokio::process::Command) {}
+}
+
+// =============================================================================
+// Registry of per-process socket sets owned by the guardian
+// =============================================================================
+
+/// Holds the [`SocketSet`] for every process that declared activation sockets,
+/// keyed by process name. Lives for the lifetime of the supervisor so the
+/// listeners outlast any individual process instance.
+#[derive(Debug, Default)]
+pub struct SocketRegistry {
+    sets: HashMap<String, SocketSet>,
+}
+
+impl SocketRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Bind and register the sockets for `name`, returning a reference to the
+    /// resulting set. A no-op returning an empty set when none are declared.
+    pub fn register(&mut self, name: &str, configs: &[SocketConfig]) -> Result<&SocketSet> {
+        if !self.sets.contains_key(name) {
+            self.sets.insert(name.to_string(), SocketSet::bind(configs)?);
+        }
+        Ok(&self.sets[name])
+    }
+
+    pub fn get(&self, name: &str) -> Option<&SocketSet> {
+        self.sets.get(name)
+    }
+}